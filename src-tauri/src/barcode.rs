@@ -41,6 +41,63 @@ pub fn validate_isbn13(isbn: &str) -> bool {
     sum % 10 == 0
 }
 
+/// Nominal (100%) X-dimension in mm for EAN-13, per GS1's SC2 magnification step
+pub const NOMINAL_X_DIM_MM: f64 = 0.330;
+/// GS1 magnification range for EAN-13: SC0 (80%, 0.264mm) to SC9 (200%, 0.660mm)
+pub const MIN_X_DIM_MM: f64 = 0.264;
+pub const MAX_X_DIM_MM: f64 = 0.660;
+
+/// Snap a desired X-dimension (mm) to the nearest value that maps cleanly to an
+/// integer number of device dots (at least one) at the given DPI. The desired
+/// value is clamped to GS1's magnification range (0.264mm-0.660mm, SC0-SC9)
+/// before snapping, so the snapped result can fall slightly outside that range.
+pub fn snap_x_dim_to_dpi(desired_mm: f64, dpi: u32) -> f64 {
+    let clamped = desired_mm.clamp(MIN_X_DIM_MM, MAX_X_DIM_MM);
+    let dots_per_module = (clamped / 25.4 * dpi as f64).round().max(1.0);
+    dots_per_module * 25.4 / dpi as f64
+}
+
+/// Compute the ISBN-13 mod-10 check digit for the first 12 digits
+fn isbn13_check_digit(first12: &[u32]) -> u32 {
+    let sum: u32 = first12.iter().enumerate().map(|(i, &d)| {
+        if i % 2 == 0 { d } else { d * 3 }
+    }).sum();
+    (10 - sum % 10) % 10
+}
+
+/// Validate an ISBN-10 mod-11 checksum and convert it to ISBN-13.
+/// Positions are weighted 10..1; a trailing `X` stands for check digit 10.
+pub fn isbn10_to_isbn13(isbn10: &str) -> Option<String> {
+    if isbn10.len() != 10 {
+        return None;
+    }
+    let chars: Vec<char> = isbn10.chars().collect();
+    if !chars[..9].iter().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let last = chars[9];
+    if !(last.is_ascii_digit() || last == 'X' || last == 'x') {
+        return None;
+    }
+
+    let digits: Vec<u32> = chars[..9].iter().map(|c| c.to_digit(10).unwrap()).collect();
+    let check_value = if last == 'X' || last == 'x' { 10 } else { last.to_digit(10).unwrap() };
+
+    let sum: u32 = digits.iter().enumerate().map(|(i, &d)| d * (10 - i as u32)).sum::<u32>() + check_value;
+    if sum % 11 != 0 {
+        return None;
+    }
+
+    let mut first12: Vec<u32> = vec![9, 7, 8];
+    first12.extend(digits);
+    let check = isbn13_check_digit(&first12);
+
+    let isbn13: String = first12.iter().chain(std::iter::once(&check))
+        .map(|d| std::char::from_digit(*d, 10).unwrap())
+        .collect();
+    Some(isbn13)
+}
+
 /// Encode EAN-13 barcode as a vector of bar widths
 /// Returns (bars, human_readable_text)
 pub fn encode_ean13(isbn: &str) -> Option<Vec<u8>> {
@@ -79,6 +136,89 @@ pub fn encode_ean13(isbn: &str) -> Option<Vec<u8>> {
     Some(modules.bytes().map(|b| b - b'0').collect())
 }
 
+/// Encode EAN-2 add-on barcode (used for price/issue supplements)
+pub fn encode_ean2(addon: &str) -> Option<Vec<u8>> {
+    if addon.len() != 2 || !addon.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let digits: [usize; 2] = [
+        addon.chars().nth(0).unwrap().to_digit(10).unwrap() as usize,
+        addon.chars().nth(1).unwrap().to_digit(10).unwrap() as usize,
+    ];
+
+    let n = 10 * digits[0] + digits[1];
+    let pattern: [u8; 2] = match n % 4 {
+        0 => [b'L', b'L'],
+        1 => [b'L', b'G'],
+        2 => [b'G', b'L'],
+        _ => [b'G', b'G'],
+    };
+
+    let mut modules = String::new();
+
+    // Start: 1011
+    modules.push_str("1011");
+
+    for (i, &ch) in pattern.iter().enumerate() {
+        let digit = digits[i];
+        if ch == b'L' {
+            modules.push_str(L_CODES[digit]);
+        } else {
+            modules.push_str(G_CODES[digit]);
+        }
+        // Separator between digits (not after last)
+        if i == 0 {
+            modules.push_str("01");
+        }
+    }
+
+    Some(modules.bytes().map(|b| b - b'0').collect())
+}
+
+/// Validate UPC-A mod-10 check digit (odd positions, 1-indexed, weighted x3)
+pub fn validate_upca(code: &str) -> bool {
+    if code.len() != 12 || !code.chars().all(|c| c.is_ascii_digit()) {
+        return false;
+    }
+    let digits: Vec<u32> = code.chars().map(|c| c.to_digit(10).unwrap()).collect();
+    let sum: u32 = digits[..11].iter().enumerate().map(|(i, &d)| {
+        if i % 2 == 0 { d * 3 } else { d }
+    }).sum();
+    let check = (10 - sum % 10) % 10;
+    check == digits[11]
+}
+
+/// Encode UPC-A barcode (12-digit North American symbology) as a vector of modules (1 = bar, 0 = space)
+pub fn encode_upca(code: &str) -> Option<Vec<u8>> {
+    if !validate_upca(code) {
+        return None;
+    }
+    let digits: Vec<usize> = code.chars().map(|c| c.to_digit(10).unwrap() as usize).collect();
+
+    let mut modules = String::new();
+
+    // Start guard: 101
+    modules.push_str("101");
+
+    // Left six digits
+    for i in 0..6 {
+        modules.push_str(L_CODES[digits[i]]);
+    }
+
+    // Center guard: 01010
+    modules.push_str("01010");
+
+    // Right six digits
+    for i in 6..12 {
+        modules.push_str(R_CODES[digits[i]]);
+    }
+
+    // End guard: 101
+    modules.push_str("101");
+
+    Some(modules.bytes().map(|b| b - b'0').collect())
+}
+
 /// Calculate EAN-5 check digit and return encoding pattern
 fn ean5_check(digits: &[usize; 5]) -> usize {
     let sum = digits[0] * 3 + digits[1] * 9 + digits[2] * 3 + digits[3] * 9 + digits[4] * 3;
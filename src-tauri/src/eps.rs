@@ -1,6 +1,8 @@
 use crate::barcode;
 
-/// Generate EPS content for ISBN barcode with optional EAN-5 add-on
+/// Generate EPS content for an EAN-13/ISBN or UPC-A barcode with optional
+/// EAN-2/EAN-5 add-on. `isbn` is a 13-digit ISBN for `symbology == "ean13"`
+/// (the default) or a 12-digit UPC-A code for `symbology == "upca"`.
 /// All coordinates are in mm, then scaled to pt via PostScript `sc` command
 pub fn generate_eps(
     isbn: &str,
@@ -8,19 +10,25 @@ pub fn generate_eps(
     bar_height_mm: f64,
     dpi: u32,
     addon_offset_mm: f64,
+    x_dim: f64,
+    symbology: &str,
 ) -> Option<String> {
-    let ean13_modules = barcode::encode_ean13(isbn)?;
-    let ean5_modules = if !addon.is_empty() {
-        barcode::encode_ean5(addon)
+    let is_upca = symbology == "upca";
+    let symbol_modules = if is_upca {
+        barcode::encode_upca(isbn)?
     } else {
-        None
+        barcode::encode_ean13(isbn)?
+    };
+    let addon_modules_opt = match addon.len() {
+        2 => barcode::encode_ean2(addon),
+        5 => barcode::encode_ean5(addon),
+        _ => None,
     };
 
-    // Module width (X-dimension) in mm
-    let x_dim: f64 = 0.33;
     let scale = 2.83464567; // mm to pt
     let font_size: f64 = 3.175; // ~9pt in mm space
-    // All values matched to reference file (978896993046013590.eps)
+    // All values matched to reference file (978896993046013590.eps) at the
+    // nominal X-dimension (0.33mm); quiet zones scale with X per GS1 (11X/7X).
     // bar_height_mm = guard bar full height (guard_bottom to bar_top)
     // Reference: guard_bottom=1.093, bar_bottom=2.743, bar_top=16.093 (=1.093+15)
     // text_y=0.0847, addon_text_y=13.7647, addon_bar_top=13.35, addon_bar_bottom=1.093
@@ -29,7 +37,7 @@ pub fn generate_eps(
     let guard_bottom: f64 = 1.093;   // guard bars & addon bars bottom (fixed)
     let bar_bottom: f64 = 2.743;     // normal bars bottom (fixed)
     let bar_top: f64 = guard_bottom + bar_height_mm; // bar_height = full guard height
-    let quiet_zone_left: f64 = 3.63;
+    let quiet_zone_left: f64 = 11.0 * x_dim;
 
     // Add-on: text baseline sits 0.4147mm above addon bar top
     // addon bar top is offset from bar_top by (font_size + 0.4147) to align text top with bar_top
@@ -40,15 +48,15 @@ pub fn generate_eps(
     let addon_bar_top: f64 = addon_text_y - addon_text_baseline_gap;
 
     // Calculate total width in mm
-    let ean13_width_mm = ean13_modules.len() as f64 * x_dim;
-    let quiet_zone_right: f64 = 2.31;
-    let addon_section_width = if ean5_modules.is_some() {
-        let addon_modules_count = ean5_modules.as_ref().unwrap().len() as f64;
+    let symbol_width_mm = symbol_modules.len() as f64 * x_dim;
+    let quiet_zone_right: f64 = 7.0 * x_dim;
+    let addon_section_width = if addon_modules_opt.is_some() {
+        let addon_modules_count = addon_modules_opt.as_ref().unwrap().len() as f64;
         addon_gap_modules * x_dim + addon_modules_count * x_dim + 2.0
     } else {
         0.0
     };
-    let total_width_mm = quiet_zone_left + ean13_width_mm + quiet_zone_right + addon_section_width;
+    let total_width_mm = quiet_zone_left + symbol_width_mm + quiet_zone_right + addon_section_width;
     let total_height_mm = bar_top + 0.5;
 
     // Convert to pt for BoundingBox
@@ -74,12 +82,13 @@ pub fn generate_eps(
     eps.push_str("% Human Readable: Yes\n");
     eps.push_str("% Text Font: Arial\n");
     eps.push_str(&format!("% Output DPI: {}\n", dpi));
-    eps.push_str("% Symbology: ISBN\n");
+    eps.push_str(&format!("% Symbology: {}\n", if is_upca { "UPC-A" } else { "ISBN" }));
     eps.push_str(&format!("% Value: {}\n", isbn));
     if !addon.is_empty() {
         eps.push_str(&format!("% Add-On: {}\n", addon));
     }
     eps.push_str(&format!("% X-Dimension: {:.8} mm\n", x_dim));
+    eps.push_str(&format!("% Magnification: {:.4}\n", x_dim / barcode::NOMINAL_X_DIM_MM));
     eps.push_str(&format!("% Bar Height: {:.8} mm\n", bar_height_mm));
     eps.push_str(&format!("% Add-On Offset: {:.4} mm\n", addon_offset_mm));
     eps.push('\n');
@@ -120,18 +129,14 @@ pub fn generate_eps(
         eps.push_str(&format!("n {:.4} {:.4} m ({}) s c\n", x, y, text));
     };
 
-    // Parse ISBN digits
+    // Parse code digits
     let digits: Vec<u32> = isbn.chars().map(|c| c.to_digit(10).unwrap()).collect();
 
-    // Draw EAN-13 bars
+    // Draw symbol bars
     let mut x = quiet_zone_left;
-    let module_count = ean13_modules.len();
-
-    // First digit (left of start guard)
-    let first_digit_x = quiet_zone_left - font_size * 0.9;
-    draw_text(&mut eps, first_digit_x, text_y, &digits[0].to_string());
+    let module_count = symbol_modules.len();
 
-    for (i, &module) in ean13_modules.iter().enumerate() {
+    for (i, &module) in symbol_modules.iter().enumerate() {
         if module == 1 {
             let is_guard = i < 3 || (i >= 45 && i <= 49) || i >= (module_count - 3);
             let y_bot = if is_guard { guard_bottom } else { bar_bottom };
@@ -140,28 +145,68 @@ pub fn generate_eps(
         x += x_dim;
     }
 
-    // Left group digits (1-6)
-    for i in 0..6 {
-        let digit = digits[i + 1];
-        let module_start = 3 + i * 7;
-        let digit_center_x = quiet_zone_left + (module_start as f64 + 3.5) * x_dim;
-        let text_x = digit_center_x - font_size * 0.3;
-        draw_text(&mut eps, text_x, text_y, &digit.to_string());
-    }
+    if is_upca {
+        // UPC-A: number system digit and check digit sit outside the guard
+        // bars in a smaller, outset face; only the middle 5+5 digits sit
+        // under the bars like an EAN-13 left/right group.
+        let upca_font_size = font_size * 0.8;
+        let set_font = |eps: &mut String, size: f64| {
+            eps.push_str(&format!("/ArialMT findfont {:.7} scalefont setfont\n", size));
+        };
+
+        let first_digit_x = quiet_zone_left - upca_font_size * 1.5;
+        set_font(&mut eps, upca_font_size);
+        draw_text(&mut eps, first_digit_x, text_y, &digits[0].to_string());
+        set_font(&mut eps, font_size);
 
-    // Right group digits (7-12)
-    for i in 0..6 {
-        let digit = digits[i + 7];
-        let module_start = 50 + i * 7;
-        let digit_center_x = quiet_zone_left + (module_start as f64 + 3.5) * x_dim;
-        let text_x = digit_center_x - font_size * 0.3;
-        draw_text(&mut eps, text_x, text_y, &digit.to_string());
+        for i in 0..5 {
+            let digit = digits[i + 1];
+            let module_start = 3 + (i + 1) * 7;
+            let digit_center_x = quiet_zone_left + (module_start as f64 + 3.5) * x_dim;
+            let text_x = digit_center_x - font_size * 0.3;
+            draw_text(&mut eps, text_x, text_y, &digit.to_string());
+        }
+
+        for i in 0..5 {
+            let digit = digits[i + 6];
+            let module_start = 50 + i * 7;
+            let digit_center_x = quiet_zone_left + (module_start as f64 + 3.5) * x_dim;
+            let text_x = digit_center_x - font_size * 0.3;
+            draw_text(&mut eps, text_x, text_y, &digit.to_string());
+        }
+
+        let last_digit_x = quiet_zone_left + symbol_width_mm + upca_font_size * 0.5;
+        set_font(&mut eps, upca_font_size);
+        draw_text(&mut eps, last_digit_x, text_y, &digits[11].to_string());
+        set_font(&mut eps, font_size);
+    } else {
+        // First digit (left of start guard)
+        let first_digit_x = quiet_zone_left - font_size * 0.9;
+        draw_text(&mut eps, first_digit_x, text_y, &digits[0].to_string());
+
+        // Left group digits (1-6)
+        for i in 0..6 {
+            let digit = digits[i + 1];
+            let module_start = 3 + i * 7;
+            let digit_center_x = quiet_zone_left + (module_start as f64 + 3.5) * x_dim;
+            let text_x = digit_center_x - font_size * 0.3;
+            draw_text(&mut eps, text_x, text_y, &digit.to_string());
+        }
+
+        // Right group digits (7-12)
+        for i in 0..6 {
+            let digit = digits[i + 7];
+            let module_start = 50 + i * 7;
+            let digit_center_x = quiet_zone_left + (module_start as f64 + 3.5) * x_dim;
+            let text_x = digit_center_x - font_size * 0.3;
+            draw_text(&mut eps, text_x, text_y, &digit.to_string());
+        }
     }
 
     // Draw EAN-5 add-on if present
-    if let Some(ref addon_modules) = ean5_modules {
+    if let Some(ref addon_modules) = addon_modules_opt {
         let addon_digits: Vec<u32> = addon.chars().map(|c| c.to_digit(10).unwrap()).collect();
-        let addon_x_start = quiet_zone_left + ean13_width_mm + addon_gap_modules * x_dim;
+        let addon_x_start = quiet_zone_left + symbol_width_mm + addon_gap_modules * x_dim;
         let mut ax = addon_x_start;
 
         for &module in addon_modules.iter() {
@@ -171,8 +216,9 @@ pub fn generate_eps(
             ax += x_dim;
         }
 
-        // Add-on digit text above bars
-        for i in 0..5 {
+        // Add-on digit text above bars (2-digit EAN-2 and 5-digit EAN-5 share the
+        // same 7-wide code + 2-wide separator layout, so the offset formula is generic)
+        for i in 0..addon_digits.len() {
             let module_offset = 4.0 + i as f64 * 9.0 + 3.5;
             let text_x = addon_x_start + module_offset * x_dim - font_size * 0.3;
             draw_text(&mut eps, text_x, addon_text_y, &addon_digits[i].to_string());
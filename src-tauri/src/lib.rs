@@ -1,10 +1,17 @@
 mod barcode;
 mod eps;
+mod png;
+mod svg;
 
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::PathBuf;
 
+fn default_format() -> String {
+    "eps".to_string()
+}
+
 #[derive(Deserialize)]
 pub struct BarcodeRequest {
     isbn: String,
@@ -12,6 +19,21 @@ pub struct BarcodeRequest {
     bar_height_mm: f64,
     dpi: u32,
     addon_offset_mm: f64,
+    #[serde(default = "default_format")]
+    format: String,
+    /// Desired X-dimension (module width) in mm; overrides `magnification` if set
+    #[serde(default)]
+    x_dim_mm: Option<f64>,
+    /// Desired magnification factor relative to `barcode::NOMINAL_X_DIM_MM` (1.0 = 100%)
+    #[serde(default)]
+    magnification: Option<f64>,
+    /// Barcode symbology: "ean13" (ISBN, default) or "upca"
+    #[serde(default = "default_symbology")]
+    symbology: String,
+}
+
+fn default_symbology() -> String {
+    "ean13".to_string()
 }
 
 #[derive(Serialize)]
@@ -19,54 +41,97 @@ pub struct BarcodeResult {
     success: bool,
     message: String,
     eps_content: Option<String>,
+    svg_content: Option<String>,
+    png_base64: Option<String>,
     file_path: Option<String>,
 }
 
-#[tauri::command]
-fn generate_barcode(request: BarcodeRequest) -> BarcodeResult {
-    // Validate ISBN
-    if request.isbn.len() != 13 || !request.isbn.chars().all(|c| c.is_ascii_digit()) {
-        return BarcodeResult {
+impl BarcodeResult {
+    fn error(message: &str) -> Self {
+        BarcodeResult {
             success: false,
-            message: "ISBN은 13자리 숫자여야 합니다.".to_string(),
+            message: message.to_string(),
             eps_content: None,
+            svg_content: None,
+            png_base64: None,
             file_path: None,
-        };
+        }
     }
+}
 
-    if !barcode::validate_isbn13(&request.isbn) {
-        return BarcodeResult {
-            success: false,
-            message: "ISBN 체크디짓이 올바르지 않습니다.".to_string(),
-            eps_content: None,
-            file_path: None,
+#[tauri::command]
+fn generate_barcode(request: BarcodeRequest) -> BarcodeResult {
+    let is_upca = request.symbology == "upca";
+
+    let isbn = if is_upca {
+        // UPC-A codes are already 12 digits; no ISBN-10/13 conversion applies
+        if !barcode::validate_upca(&request.isbn) {
+            return BarcodeResult::error("UPC-A 체크디짓이 올바르지 않습니다.");
+        }
+        request.isbn.clone()
+    } else {
+        // Accept legacy ISBN-10 input and auto-convert to ISBN-13
+        let isbn = if request.isbn.len() == 10 {
+            match barcode::isbn10_to_isbn13(&request.isbn) {
+                Some(isbn13) => isbn13,
+                None => return BarcodeResult::error("ISBN-10 체크디짓이 올바르지 않습니다."),
+            }
+        } else {
+            request.isbn.clone()
         };
-    }
 
-    // Validate add-on
+        if isbn.len() != 13 || !isbn.chars().all(|c| c.is_ascii_digit()) {
+            return BarcodeResult::error("ISBN은 13자리 숫자여야 합니다.");
+        }
+
+        if !barcode::validate_isbn13(&isbn) {
+            return BarcodeResult::error("ISBN 체크디짓이 올바르지 않습니다.");
+        }
+
+        isbn
+    };
+
+    // Validate add-on (EAN-2 price/issue code or EAN-5 supplement)
     if !request.addon.is_empty()
-        && (request.addon.len() != 5 || !request.addon.chars().all(|c| c.is_ascii_digit()))
+        && (![2, 5].contains(&request.addon.len()) || !request.addon.chars().all(|c| c.is_ascii_digit()))
     {
-        return BarcodeResult {
-            success: false,
-            message: "분류번호는 5자리 숫자여야 합니다.".to_string(),
-            eps_content: None,
-            file_path: None,
-        };
+        return BarcodeResult::error("분류번호는 2자리 또는 5자리 숫자여야 합니다.");
     }
 
-    match eps::generate_eps(&request.isbn, &request.addon, request.bar_height_mm, request.dpi, request.addon_offset_mm) {
-        Some(content) => BarcodeResult {
-            success: true,
-            message: "바코드가 생성되었습니다.".to_string(),
-            eps_content: Some(content),
-            file_path: None,
+    // Desired X-dimension, snapped to a whole number of device dots at the target DPI
+    let desired_x_dim = request
+        .x_dim_mm
+        .or_else(|| request.magnification.map(|m| m * barcode::NOMINAL_X_DIM_MM))
+        .unwrap_or(barcode::NOMINAL_X_DIM_MM);
+    let x_dim = barcode::snap_x_dim_to_dpi(desired_x_dim, request.dpi);
+
+    match request.format.as_str() {
+        "svg" => match svg::generate_svg(&isbn, &request.addon, request.bar_height_mm, request.dpi, request.addon_offset_mm, x_dim, &request.symbology) {
+            Some(content) => BarcodeResult {
+                success: true,
+                message: "바코드가 생성되었습니다.".to_string(),
+                svg_content: Some(content),
+                ..BarcodeResult::error("")
+            },
+            None => BarcodeResult::error("바코드 생성에 실패했습니다."),
         },
-        None => BarcodeResult {
-            success: false,
-            message: "바코드 생성에 실패했습니다.".to_string(),
-            eps_content: None,
-            file_path: None,
+        "png" => match png::generate_png(&isbn, &request.addon, request.bar_height_mm, request.dpi, request.addon_offset_mm, x_dim, &request.symbology) {
+            Some(bytes) => BarcodeResult {
+                success: true,
+                message: "바코드가 생성되었습니다.".to_string(),
+                png_base64: Some(BASE64.encode(bytes)),
+                ..BarcodeResult::error("")
+            },
+            None => BarcodeResult::error("바코드 생성에 실패했습니다."),
+        },
+        _ => match eps::generate_eps(&isbn, &request.addon, request.bar_height_mm, request.dpi, request.addon_offset_mm, x_dim, &request.symbology) {
+            Some(content) => BarcodeResult {
+                success: true,
+                message: "바코드가 생성되었습니다.".to_string(),
+                eps_content: Some(content),
+                ..BarcodeResult::error("")
+            },
+            None => BarcodeResult::error("바코드 생성에 실패했습니다."),
         },
     }
 }
@@ -78,15 +143,28 @@ fn save_eps(content: String, file_path: String) -> BarcodeResult {
         Ok(_) => BarcodeResult {
             success: true,
             message: format!("저장 완료: {}", file_path),
-            eps_content: None,
             file_path: Some(file_path),
+            ..BarcodeResult::error("")
         },
-        Err(e) => BarcodeResult {
-            success: false,
-            message: format!("저장 실패: {}", e),
-            eps_content: None,
-            file_path: None,
+        Err(e) => BarcodeResult::error(&format!("저장 실패: {}", e)),
+    }
+}
+
+#[tauri::command]
+fn save_png(png_base64: String, file_path: String) -> BarcodeResult {
+    let bytes = match BASE64.decode(&png_base64) {
+        Ok(bytes) => bytes,
+        Err(e) => return BarcodeResult::error(&format!("저장 실패: {}", e)),
+    };
+    let path = PathBuf::from(&file_path);
+    match fs::write(&path, &bytes) {
+        Ok(_) => BarcodeResult {
+            success: true,
+            message: format!("저장 완료: {}", file_path),
+            file_path: Some(file_path),
+            ..BarcodeResult::error("")
         },
+        Err(e) => BarcodeResult::error(&format!("저장 실패: {}", e)),
     }
 }
 
@@ -95,7 +173,7 @@ pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_fs::init())
-        .invoke_handler(tauri::generate_handler![generate_barcode, save_eps])
+        .invoke_handler(tauri::generate_handler![generate_barcode, save_eps, save_png])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
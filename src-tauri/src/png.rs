@@ -0,0 +1,211 @@
+use crate::barcode;
+use image::{ImageBuffer, Rgba, RgbaImage};
+use std::io::Cursor;
+
+/// 5x7 bitmap font for digits 0-9, OCR-B-style, one row per scanline (MSB = leftmost column)
+const DIGIT_FONT: [[u8; 7]; 10] = [
+    [0b01110, 0b10001, 0b10011, 0b10101, 0b11001, 0b10001, 0b01110], // 0
+    [0b00100, 0b01100, 0b00100, 0b00100, 0b00100, 0b00100, 0b01110], // 1
+    [0b01110, 0b10001, 0b00001, 0b00010, 0b00100, 0b01000, 0b11111], // 2
+    [0b11111, 0b00010, 0b00100, 0b00010, 0b00001, 0b10001, 0b01110], // 3
+    [0b00010, 0b00110, 0b01010, 0b10010, 0b11111, 0b00010, 0b00010], // 4
+    [0b11111, 0b10000, 0b11110, 0b00001, 0b00001, 0b10001, 0b01110], // 5
+    [0b00110, 0b01000, 0b10000, 0b11110, 0b10001, 0b10001, 0b01110], // 6
+    [0b11111, 0b00001, 0b00010, 0b00100, 0b01000, 0b01000, 0b01000], // 7
+    [0b01110, 0b10001, 0b10001, 0b01110, 0b10001, 0b10001, 0b01110], // 8
+    [0b01110, 0b10001, 0b10001, 0b01111, 0b00001, 0b00010, 0b01100], // 9
+];
+
+/// Convert an mm measurement to whole device pixels at the given DPI
+fn mm_to_px(mm: f64, dpi: u32) -> u32 {
+    (mm / 25.4 * dpi as f64).round().max(0.0) as u32
+}
+
+/// Convert an mm measurement to at least 1 device pixel, so thin modules never vanish
+fn mm_to_px_min1(mm: f64, dpi: u32) -> u32 {
+    mm_to_px(mm, dpi).max(1)
+}
+
+fn fill_rect(img: &mut RgbaImage, x: u32, y: u32, w: u32, h: u32) {
+    let black = Rgba([0, 0, 0, 255]);
+    for py in y..(y + h).min(img.height()) {
+        for px in x..(x + w).min(img.width()) {
+            img.put_pixel(px, py, black);
+        }
+    }
+}
+
+/// Draw a single digit glyph scaled so each font pixel is `scale` device pixels
+fn draw_digit(img: &mut RgbaImage, digit: u32, x: u32, y: u32, scale: u32) {
+    let glyph = &DIGIT_FONT[digit as usize];
+    for (row, bits) in glyph.iter().enumerate() {
+        for col in 0..5 {
+            if bits & (1 << (4 - col)) != 0 {
+                fill_rect(img, x + col as u32 * scale, y + row as u32 * scale, scale, scale);
+            }
+        }
+    }
+}
+
+fn draw_text(img: &mut RgbaImage, text: &str, x: u32, y: u32, scale: u32) {
+    let advance = 6 * scale; // 5 columns + 1 column gap
+    for (i, ch) in text.chars().enumerate() {
+        if let Some(digit) = ch.to_digit(10) {
+            draw_digit(img, digit, x + i as u32 * advance, y, scale);
+        }
+    }
+}
+
+/// Rasterize an EAN-13/ISBN or UPC-A barcode (with optional EAN-2/EAN-5
+/// add-on) to a PNG at the requested DPI. `isbn` is a 13-digit ISBN for
+/// `symbology == "ean13"` (the default) or a 12-digit UPC-A code for
+/// `symbology == "upca"`. Bar edges are snapped to whole pixels and every
+/// module is guaranteed at least 1px wide, so bars don't vanish or smear at
+/// low DPI ("half-integer scaling" problem).
+pub fn generate_png(
+    isbn: &str,
+    addon: &str,
+    bar_height_mm: f64,
+    dpi: u32,
+    addon_offset_mm: f64,
+    x_dim: f64,
+    symbology: &str,
+) -> Option<Vec<u8>> {
+    let is_upca = symbology == "upca";
+    let symbol_modules = if is_upca {
+        barcode::encode_upca(isbn)?
+    } else {
+        barcode::encode_ean13(isbn)?
+    };
+    let addon_modules_opt = match addon.len() {
+        2 => barcode::encode_ean2(addon),
+        5 => barcode::encode_ean5(addon),
+        _ => None,
+    };
+
+    let font_size: f64 = 3.175;
+
+    let guard_bottom: f64 = 1.093;
+    let bar_bottom: f64 = 2.743;
+    let bar_top: f64 = guard_bottom + bar_height_mm;
+    let quiet_zone_left: f64 = 11.0 * x_dim; // GS1 left quiet zone = 11X
+
+    let addon_gap_modules: f64 = 7.0;
+    let addon_bar_bottom: f64 = guard_bottom;
+    let addon_text_baseline_gap: f64 = 0.4147;
+    let addon_text_y: f64 = bar_top - font_size + addon_offset_mm;
+    let addon_bar_top: f64 = addon_text_y - addon_text_baseline_gap;
+
+    let symbol_width_mm = symbol_modules.len() as f64 * x_dim;
+    let quiet_zone_right: f64 = 7.0 * x_dim; // GS1 right quiet zone = 7X
+    let addon_section_width = if let Some(ref m) = addon_modules_opt {
+        addon_gap_modules * x_dim + m.len() as f64 * x_dim + 2.0
+    } else {
+        0.0
+    };
+    let total_width_mm = quiet_zone_left + symbol_width_mm + quiet_zone_right + addon_section_width;
+    let total_height_mm = bar_top + 0.5;
+
+    let width_px = mm_to_px(total_width_mm, dpi).max(1);
+    let height_px = mm_to_px(total_height_mm, dpi).max(1);
+    let px_per_module = mm_to_px_min1(x_dim, dpi);
+
+    let mut img: RgbaImage = ImageBuffer::from_pixel(width_px, height_px, Rgba([255, 255, 255, 255]));
+
+    // PostScript-style y-up coordinates flip to image y-down when drawing
+    let y_to_px = |y_mm: f64| -> u32 { mm_to_px(total_height_mm - y_mm, dpi) };
+
+    // Draw symbol bars
+    let module_count = symbol_modules.len();
+    for (i, &module) in symbol_modules.iter().enumerate() {
+        if module == 1 {
+            let is_guard = i < 3 || (i >= 45 && i <= 49) || i >= (module_count - 3);
+            let y_bot = if is_guard { guard_bottom } else { bar_bottom };
+            let x_px = mm_to_px(quiet_zone_left + i as f64 * x_dim, dpi);
+            let top_px = y_to_px(bar_top);
+            let bot_px = y_to_px(y_bot);
+            fill_rect(&mut img, x_px, top_px, px_per_module, bot_px.saturating_sub(top_px).max(1));
+        }
+    }
+
+    // Digit glyph scale: font box is 5 columns wide, aim for roughly font_size mm tall
+    let glyph_scale = mm_to_px_min1(font_size / 7.0, dpi);
+
+    let digits: Vec<u32> = isbn.chars().map(|c| c.to_digit(10).unwrap()).collect();
+
+    if is_upca {
+        // UPC-A: number system digit and check digit sit outside the guard
+        // bars in a smaller, outset face; only the middle 5+5 digits sit
+        // under the bars like an EAN-13 left/right group.
+        let upca_glyph_scale = mm_to_px_min1(font_size * 0.8 / 7.0, dpi);
+
+        let first_digit_x = mm_to_px((quiet_zone_left - font_size * 1.2).max(0.0), dpi);
+        draw_text(&mut img, &digits[0].to_string(), first_digit_x, y_to_px(font_size), upca_glyph_scale);
+
+        for i in 0..5 {
+            let digit = digits[i + 1];
+            let module_start = 3 + (i + 1) * 7;
+            let digit_center_x = quiet_zone_left + (module_start as f64 + 3.5) * x_dim;
+            let text_x = mm_to_px(digit_center_x - font_size * 0.3, dpi);
+            draw_text(&mut img, &digit.to_string(), text_x, y_to_px(font_size), glyph_scale);
+        }
+
+        for i in 0..5 {
+            let digit = digits[i + 6];
+            let module_start = 50 + i * 7;
+            let digit_center_x = quiet_zone_left + (module_start as f64 + 3.5) * x_dim;
+            let text_x = mm_to_px(digit_center_x - font_size * 0.3, dpi);
+            draw_text(&mut img, &digit.to_string(), text_x, y_to_px(font_size), glyph_scale);
+        }
+
+        let last_digit_x = mm_to_px(quiet_zone_left + symbol_width_mm + font_size * 0.4, dpi);
+        draw_text(&mut img, &digits[11].to_string(), last_digit_x, y_to_px(font_size), upca_glyph_scale);
+    } else {
+        let first_digit_x = mm_to_px((quiet_zone_left - font_size * 0.9).max(0.0), dpi);
+        draw_text(&mut img, &digits[0].to_string(), first_digit_x, y_to_px(font_size), glyph_scale);
+
+        for i in 0..6 {
+            let digit = digits[i + 1];
+            let module_start = 3 + i * 7;
+            let digit_center_x = quiet_zone_left + (module_start as f64 + 3.5) * x_dim;
+            let text_x = mm_to_px(digit_center_x - font_size * 0.3, dpi);
+            draw_text(&mut img, &digit.to_string(), text_x, y_to_px(font_size), glyph_scale);
+        }
+
+        for i in 0..6 {
+            let digit = digits[i + 7];
+            let module_start = 50 + i * 7;
+            let digit_center_x = quiet_zone_left + (module_start as f64 + 3.5) * x_dim;
+            let text_x = mm_to_px(digit_center_x - font_size * 0.3, dpi);
+            draw_text(&mut img, &digit.to_string(), text_x, y_to_px(font_size), glyph_scale);
+        }
+    }
+
+    // Draw EAN-2/EAN-5 add-on if present
+    if let Some(ref addon_modules) = addon_modules_opt {
+        let addon_digits: Vec<u32> = addon.chars().map(|c| c.to_digit(10).unwrap()).collect();
+        let addon_x_start = quiet_zone_left + symbol_width_mm + addon_gap_modules * x_dim;
+
+        for (i, &module) in addon_modules.iter().enumerate() {
+            if module == 1 {
+                let x_px = mm_to_px(addon_x_start + i as f64 * x_dim, dpi);
+                let top_px = y_to_px(addon_bar_top);
+                let bot_px = y_to_px(addon_bar_bottom);
+                fill_rect(&mut img, x_px, top_px, px_per_module, bot_px.saturating_sub(top_px).max(1));
+            }
+        }
+
+        // 2-digit EAN-2 and 5-digit EAN-5 share the same 7-wide code + 2-wide
+        // separator layout, so the offset formula is generic. Glyphs are drawn
+        // from their top edge, so the top sits one font height above the baseline.
+        for i in 0..addon_digits.len() {
+            let module_offset = 4.0 + i as f64 * 9.0 + 3.5;
+            let text_x = mm_to_px(addon_x_start + module_offset * x_dim - font_size * 0.3, dpi);
+            draw_text(&mut img, &addon_digits[i].to_string(), text_x, y_to_px(addon_text_y + font_size), glyph_scale);
+        }
+    }
+
+    let mut bytes: Vec<u8> = Vec::new();
+    img.write_to(&mut Cursor::new(&mut bytes), image::ImageFormat::Png).ok()?;
+    Some(bytes)
+}
@@ -0,0 +1,196 @@
+use crate::barcode;
+
+/// Generate SVG content for an EAN-13/ISBN or UPC-A barcode with optional
+/// EAN-2/EAN-5 add-on. `isbn` is a 13-digit ISBN for `symbology == "ean13"`
+/// (the default) or a 12-digit UPC-A code for `symbology == "upca"`.
+/// Mirrors the coordinate math in `eps::generate_eps` so both renderers stay
+/// visually identical; SVG's y-axis points down, so bar/text y values are
+/// flipped against `total_height_mm` before being written out.
+pub fn generate_svg(
+    isbn: &str,
+    addon: &str,
+    bar_height_mm: f64,
+    dpi: u32,
+    addon_offset_mm: f64,
+    x_dim: f64,
+    symbology: &str,
+) -> Option<String> {
+    let is_upca = symbology == "upca";
+    let symbol_modules = if is_upca {
+        barcode::encode_upca(isbn)?
+    } else {
+        barcode::encode_ean13(isbn)?
+    };
+    let addon_modules_opt = match addon.len() {
+        2 => barcode::encode_ean2(addon),
+        5 => barcode::encode_ean5(addon),
+        _ => None,
+    };
+
+    let font_size: f64 = 3.175; // ~9pt in mm space
+
+    let text_y: f64 = 0.0847;       // ISBN text baseline (fixed)
+    let guard_bottom: f64 = 1.093;   // guard bars & addon bars bottom (fixed)
+    let bar_bottom: f64 = 2.743;     // normal bars bottom (fixed)
+    let bar_top: f64 = guard_bottom + bar_height_mm; // bar_height = full guard height
+    let quiet_zone_left: f64 = 11.0 * x_dim; // GS1 left quiet zone = 11X
+
+    let addon_gap_modules: f64 = 7.0;
+    let addon_bar_bottom: f64 = guard_bottom; // fixed, same as guard
+    let addon_text_baseline_gap: f64 = 0.4147; // gap between addon bar top and text baseline
+    let addon_text_y: f64 = bar_top - font_size + addon_offset_mm;
+    let addon_bar_top: f64 = addon_text_y - addon_text_baseline_gap;
+
+    // Calculate total width in mm
+    let symbol_width_mm = symbol_modules.len() as f64 * x_dim;
+    let quiet_zone_right: f64 = 7.0 * x_dim; // GS1 right quiet zone = 7X
+    let addon_section_width = if addon_modules_opt.is_some() {
+        let addon_modules_count = addon_modules_opt.as_ref().unwrap().len() as f64;
+        addon_gap_modules * x_dim + addon_modules_count * x_dim + 2.0
+    } else {
+        0.0
+    };
+    let total_width_mm = quiet_zone_left + symbol_width_mm + quiet_zone_right + addon_section_width;
+    let total_height_mm = bar_top + 0.5;
+
+    let mut svg = String::new();
+
+    svg.push_str(&format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 {:.4} {:.4}\" data-dpi=\"{}\">\n",
+        total_width_mm, total_height_mm, dpi
+    ));
+    svg.push_str(&format!(
+        "<rect x=\"0\" y=\"0\" width=\"{:.4}\" height=\"{:.4}\" fill=\"#ffffff\"/>\n",
+        total_width_mm, total_height_mm
+    ));
+
+    // Helper: convert a PostScript-style y-up bar (y_bot..y_top) into an SVG rect
+    let draw_bar = |svg: &mut String, x: f64, w: f64, y_bot: f64, y_top: f64| {
+        let y = total_height_mm - y_top;
+        let h = y_top - y_bot;
+        svg.push_str(&format!(
+            "<rect x=\"{:.4}\" y=\"{:.4}\" width=\"{:.4}\" height=\"{:.4}\" fill=\"#000000\"/>\n",
+            x, y, w, h
+        ));
+    };
+
+    let draw_text_sized = |svg: &mut String, x: f64, y: f64, size: f64, text: &str| {
+        let svg_y = total_height_mm - y;
+        svg.push_str(&format!(
+            "<text x=\"{:.4}\" y=\"{:.4}\" font-family=\"Arial\" font-size=\"{:.4}\">{}</text>\n",
+            x, svg_y, size, text
+        ));
+    };
+    let draw_text = |svg: &mut String, x: f64, y: f64, text: &str| {
+        draw_text_sized(svg, x, y, font_size, text);
+    };
+
+    // Parse code digits
+    let digits: Vec<u32> = isbn.chars().map(|c| c.to_digit(10).unwrap()).collect();
+
+    // Draw symbol bars, merging consecutive set modules into a single rect
+    let module_count = symbol_modules.len();
+    let mut i = 0;
+    while i < module_count {
+        if symbol_modules[i] == 1 {
+            let run_start = i;
+            while i < module_count && symbol_modules[i] == 1 {
+                i += 1;
+            }
+            let run_end = i; // exclusive
+            let is_guard = run_start < 3 || (run_start >= 45 && run_start <= 49) || run_start >= (module_count - 3);
+            let y_bot = if is_guard { guard_bottom } else { bar_bottom };
+            let x = quiet_zone_left + run_start as f64 * x_dim;
+            let w = (run_end - run_start) as f64 * x_dim;
+            draw_bar(&mut svg, x, w, y_bot, bar_top);
+        } else {
+            i += 1;
+        }
+    }
+
+    if is_upca {
+        // UPC-A: number system digit and check digit sit outside the guard
+        // bars in a smaller, outset face; only the middle 5+5 digits sit
+        // under the bars like an EAN-13 left/right group.
+        let upca_font_size = font_size * 0.8;
+
+        let first_digit_x = quiet_zone_left - upca_font_size * 1.5;
+        draw_text_sized(&mut svg, first_digit_x, text_y, upca_font_size, &digits[0].to_string());
+
+        for i in 0..5 {
+            let digit = digits[i + 1];
+            let module_start = 3 + (i + 1) * 7;
+            let digit_center_x = quiet_zone_left + (module_start as f64 + 3.5) * x_dim;
+            let text_x = digit_center_x - font_size * 0.3;
+            draw_text(&mut svg, text_x, text_y, &digit.to_string());
+        }
+
+        for i in 0..5 {
+            let digit = digits[i + 6];
+            let module_start = 50 + i * 7;
+            let digit_center_x = quiet_zone_left + (module_start as f64 + 3.5) * x_dim;
+            let text_x = digit_center_x - font_size * 0.3;
+            draw_text(&mut svg, text_x, text_y, &digit.to_string());
+        }
+
+        let last_digit_x = quiet_zone_left + symbol_width_mm + upca_font_size * 0.5;
+        draw_text_sized(&mut svg, last_digit_x, text_y, upca_font_size, &digits[11].to_string());
+    } else {
+        // First digit (left of start guard)
+        let first_digit_x = quiet_zone_left - font_size * 0.9;
+        draw_text(&mut svg, first_digit_x, text_y, &digits[0].to_string());
+
+        // Left group digits (1-6)
+        for i in 0..6 {
+            let digit = digits[i + 1];
+            let module_start = 3 + i * 7;
+            let digit_center_x = quiet_zone_left + (module_start as f64 + 3.5) * x_dim;
+            let text_x = digit_center_x - font_size * 0.3;
+            draw_text(&mut svg, text_x, text_y, &digit.to_string());
+        }
+
+        // Right group digits (7-12)
+        for i in 0..6 {
+            let digit = digits[i + 7];
+            let module_start = 50 + i * 7;
+            let digit_center_x = quiet_zone_left + (module_start as f64 + 3.5) * x_dim;
+            let text_x = digit_center_x - font_size * 0.3;
+            draw_text(&mut svg, text_x, text_y, &digit.to_string());
+        }
+    }
+
+    // Draw EAN-2/EAN-5 add-on if present
+    if let Some(ref addon_modules) = addon_modules_opt {
+        let addon_digits: Vec<u32> = addon.chars().map(|c| c.to_digit(10).unwrap()).collect();
+        let addon_x_start = quiet_zone_left + symbol_width_mm + addon_gap_modules * x_dim;
+
+        let addon_module_count = addon_modules.len();
+        let mut j = 0;
+        while j < addon_module_count {
+            if addon_modules[j] == 1 {
+                let run_start = j;
+                while j < addon_module_count && addon_modules[j] == 1 {
+                    j += 1;
+                }
+                let run_end = j;
+                let x = addon_x_start + run_start as f64 * x_dim;
+                let w = (run_end - run_start) as f64 * x_dim;
+                draw_bar(&mut svg, x, w, addon_bar_bottom, addon_bar_top);
+            } else {
+                j += 1;
+            }
+        }
+
+        // Add-on digit text above bars (2-digit EAN-2 and 5-digit EAN-5 share the
+        // same 7-wide code + 2-wide separator layout, so the offset formula is generic)
+        for i in 0..addon_digits.len() {
+            let module_offset = 4.0 + i as f64 * 9.0 + 3.5;
+            let text_x = addon_x_start + module_offset * x_dim - font_size * 0.3;
+            draw_text(&mut svg, text_x, addon_text_y, &addon_digits[i].to_string());
+        }
+    }
+
+    svg.push_str("</svg>\n");
+
+    Some(svg)
+}